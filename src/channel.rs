@@ -1,11 +1,17 @@
 pub mod angle;
 
-use num_traits::NumCast;
-
-use crate::{cuwf, cuwtf};
+use num_traits::{Float, NumCast};
 
 /// A trait for color channels
 pub trait Channel: Sized + PartialOrd + NumCast {
+    /// The high-precision floating point type used as a pivot when converting this channel
+    /// to/from other channel types
+    ///
+    /// This defaults to `f32` for the integer channels, but `f64` channels pivot through `f64`
+    /// themselves, so a conversion chain between high-precision colors isn't truncated through
+    /// an `f32` bottleneck along the way.
+    type Float: Float + NumCast;
+
     /// Tells whether this is a channel with integer value
     ///
     /// If false the channel has a floating point value.
@@ -28,10 +34,18 @@ pub trait Channel: Sized + PartialOrd + NumCast {
     ///
     /// The channel's range is taken into account, eg. 1.0 in f32 is converted into 255 in u8.
     ///
-    /// The values will be made to fit into their range.
+    /// The values will be made to fit into their range. The conversion is pivoted through
+    /// `Self::Float`, so converting a high-precision channel doesn't lose precision by
+    /// round-tripping through `f32`.
     fn conv<T: Channel>(self) -> T {
-        let float = cuwtf(self.clamp()) / cuwtf(Self::ch_max()) * cuwtf(T::ch_max());
-        cuwf(if T::INTEGER { float.round() } else { float })
+        let value: Self::Float = NumCast::from(self.clamp()).unwrap();
+        let self_max: Self::Float = NumCast::from(Self::ch_max()).unwrap();
+        let target_max: Self::Float = NumCast::from(T::ch_max()).unwrap();
+
+        let float = value / self_max * target_max;
+        let float = if T::INTEGER { float.round() } else { float };
+
+        NumCast::from(float).unwrap()
     }
 
     /// Return whether this value is inside the channel's allowed range
@@ -54,6 +68,8 @@ pub trait Channel: Sized + PartialOrd + NumCast {
 macro_rules! impl_uint_channels {
     ( $( $type:ty ),* ) => { $(
         impl Channel for $type {
+            type Float = f32;
+
             const INTEGER: bool = true;
             fn ch_max() -> Self { <$type>::max_value() }
             fn ch_mid() -> Self { <$type>::max_value() / 2 }
@@ -65,6 +81,23 @@ macro_rules! impl_uint_channels {
 impl_uint_channels!(u8, u16, u32);
 
 impl Channel for f32 {
+    type Float = f32;
+
+    const INTEGER: bool = false;
+    fn ch_max() -> Self {
+        1.0
+    }
+    fn ch_mid() -> Self {
+        0.5
+    }
+    fn ch_zero() -> Self {
+        0.0
+    }
+}
+
+impl Channel for f64 {
+    type Float = f64;
+
     const INTEGER: bool = false;
     fn ch_max() -> Self {
         1.0
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::*;
+
+/// A CMYK (cyan, magenta, yellow, key) color
+///
+/// This is the subtractive, print-oriented counterpart to the additive RGB models.
+///
+/// ## Type arguments
+/// `T` is the type of all four channels.
+///
+/// `S` is this color's colorspace.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct CMYKColor<T, S> {
+    pub c: T,
+    pub m: T,
+    pub y: T,
+    pub k: T,
+    _space: PhantomData<S>
+}
+
+impl<T, S> CMYKColor<T, S> {
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (T, T, T, T) {
+        (self.c, self.m, self.y, self.k)
+    }
+}
+
+impl<T, S> CMYKColor<T, S>
+    where Self: Color
+{
+    /// Create a new CMYK value.
+    ///
+    /// The value is normalized on creation.
+    pub fn new(c: T, m: T, y: T, k: T) -> Self {
+        CMYKColor { c, m, y, k, _space: PhantomData }.normalize()
+    }
+}
+
+impl<T: Channel, S> CMYKColor<T, S> {
+    /// Transform this color into RGB form
+    ///
+    /// This should be done to a normalized CMYK color.
+    ///
+    /// The math is carried out in `T::Float`, so a high-precision color (eg. one with `f64`
+    /// channels) doesn't get truncated through an `f32` bottleneck in the middle of the
+    /// conversion.
+    pub fn rgb(self) -> RGBColor<T, S> {
+        let c: T::Float = NumCast::from(self.c).unwrap();
+        let m: T::Float = NumCast::from(self.m).unwrap();
+        let y: T::Float = NumCast::from(self.y).unwrap();
+        let k: T::Float = NumCast::from(self.k).unwrap();
+        let one = T::Float::one();
+
+        let r = (one - c) * (one - k);
+        let g = (one - m) * (one - k);
+        let b = (one - y) * (one - k);
+
+        (NumCast::from(r).unwrap(), NumCast::from(g).unwrap(), NumCast::from(b).unwrap()).into()
+    }
+
+    #[inline]
+    pub fn conv<T2: Channel>(self) -> CMYKColor<T2, S> {
+        CMYKColor { c: self.c.conv(), m: self.m.conv(), y: self.y.conv(), k: self.k.conv(), _space: PhantomData }
+    }
+}
+
+impl<T: Channel, S> RGBColor<T, S> {
+    /// Transform this color into CMYK form
+    ///
+    /// The math is carried out in `T::Float`, so a high-precision color (eg. one with `f64`
+    /// channels) doesn't get truncated through an `f32` bottleneck in the middle of the
+    /// conversion.
+    pub fn cmyk(self) -> CMYKColor<T, S> {
+        let r: T::Float = NumCast::from(self.r).unwrap();
+        let g: T::Float = NumCast::from(self.g).unwrap();
+        let b: T::Float = NumCast::from(self.b).unwrap();
+        let zero = T::Float::zero();
+        let one = T::Float::one();
+
+        let k = one - r.max(g).max(b);
+
+        let (c, m, y) = if k == one {
+            (zero, zero, zero)
+        } else {
+            ((one - r - k) / (one - k),
+             (one - g - k) / (one - k),
+             (one - b - k) / (one - k))
+        };
+
+        CMYKColor {
+            c: NumCast::from(c).unwrap(),
+            m: NumCast::from(m).unwrap(),
+            y: NumCast::from(y).unwrap(),
+            k: NumCast::from(k).unwrap(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<T: Channel, S> Color for CMYKColor<T, S>
+    where Self: Clone
+{
+    /// Normalize the color's values by putting each channel into its proper range
+    fn normalize(self) -> Self {
+        let (c, m, y, k) = self.tuple();
+        CMYKColor {
+            c: c.to_range(),
+            m: m.to_range(),
+            y: y.to_range(),
+            k: k.to_range(),
+            _space: PhantomData
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        let (c, m, y, k) = self.clone().tuple();
+        c.in_range() && m.in_range() && y.in_range() && k.in_range()
+    }
+}
+
+impl<T: Channel> From<BaseColor> for CMYKColor<T, SRGBSpace>
+    where Self: Color
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<f32, SRGBSpace>::from(base_color).cmyk().conv()
+    }
+}
+
+impl<T: Channel> From<BaseColor> for CMYKColor<T, LinearSpace>
+    where Self: Color
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<f32, LinearSpace>::from(base_color).cmyk().conv()
+    }
+}
+
+impl<T: Channel, S> From<(T, T, T, T)> for CMYKColor<T, S>
+    where Self: Color
+{
+    fn from(tuple: (T, T, T, T)) -> Self {
+        let (c, m, y, k) = tuple;
+        CMYKColor::new(c, m, y, k)
+    }
+}
+
+impl<T: Clone + Channel, S> From<&(T, T, T, T)> for CMYKColor<T, S>
+    where Self: Color
+{
+    fn from(tuple: &(T, T, T, T)) -> Self {
+        let (c, m, y, k) = tuple.clone();
+        CMYKColor::new(c, m, y, k)
+    }
+}
+
+impl<T: Channel, S> Default for CMYKColor<T, S> {
+    fn default() -> Self {
+        CMYKColor {
+            c: T::ch_zero(),
+            m: T::ch_zero(),
+            y: T::ch_zero(),
+            k: T::ch_max(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<T: Clone, S> Clone for CMYKColor<T, S> {
+    fn clone(&self) -> Self {
+        CMYKColor {
+            c: self.c.clone(),
+            m: self.m.clone(),
+            y: self.y.clone(),
+            k: self.k.clone(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<T: Copy, S> Copy for CMYKColor<T, S> {}
+
+// TODO make more generic
+impl<S> fmt::Display for CMYKColor<f32, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>5.1}%,{:>5.1}%,{:>5.1}%,{:>5.1}%",
+            self.c * 100.0, self.m * 100.0, self.y * 100.0, self.k * 100.0)
+    }
+}
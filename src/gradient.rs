@@ -0,0 +1,119 @@
+use crate::*;
+
+/// Linear interpolation between two values of the same color model
+pub trait Lerp: Sized {
+    /// Mixes `self` and `other` by `t`, where `t = 0.0` yields `self` and `t = 1.0` yields `other`
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl Lerp for AngleDeg<f32> {
+    /// Interpolates along the shorter arc of the hue circle, wrapping at 360°/0°
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let diff = ((other.0 - self.0 + 540.0) % 360.0) - 180.0;
+        AngleDeg((self.0 + diff * t).rem_euclid(360.0))
+    }
+}
+
+impl<S> Lerp for RGBColor<f32, S> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (r1, g1, b1) = self.tuple();
+        let (r2, g2, b2) = other.tuple();
+
+        (r1 + (r2 - r1) * t,
+         g1 + (g2 - g1) * t,
+         b1 + (b2 - b1) * t).into()
+    }
+}
+
+impl<S> Lerp for HSVColor<AngleDeg<f32>, f32, S> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (h1, s1, v1) = self.tuple();
+        let (h2, s2, v2) = other.tuple();
+
+        HSVColor::new(h1.lerp(h2, t), s1 + (s2 - s1) * t, v1 + (v2 - v1) * t)
+    }
+}
+
+impl<S> Lerp for HSLColor<AngleDeg<f32>, f32, S> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (h1, s1, l1) = self.tuple();
+        let (h2, s2, l2) = other.tuple();
+
+        HSLColor::new(h1.lerp(h2, t), s1 + (s2 - s1) * t, l1 + (l2 - l1) * t)
+    }
+}
+
+impl<S> Lerp for HWBColor<AngleDeg<f32>, f32, S> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (h1, w1, b1) = self.tuple();
+        let (h2, w2, b2) = other.tuple();
+
+        HWBColor::new(h1.lerp(h2, t), w1 + (w2 - w1) * t, b1 + (b2 - b1) * t)
+    }
+}
+
+impl<S> Lerp for CMYKColor<f32, S> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (c1, m1, y1, k1) = self.tuple();
+        let (c2, m2, y2, k2) = other.tuple();
+
+        CMYKColor::new(c1 + (c2 - c1) * t, m1 + (m2 - m1) * t, y1 + (y2 - y1) * t, k1 + (k2 - k1) * t)
+    }
+}
+
+/// A multi-stop color gradient
+///
+/// Stops are kept sorted by position so `.get(t)` can find the pair surrounding `t` and
+/// interpolate between them with `Lerp`.
+pub struct Gradient<C> {
+    stops: Vec<(f32, C)>,
+}
+
+impl<C: Lerp + Clone> Gradient<C> {
+    /// Builds a gradient out of `(position, color)` stops
+    ///
+    /// Stops don't need to be given in order, they are sorted on construction.
+    pub fn new(mut stops: Vec<(f32, C)>) -> Self {
+        assert!(!stops.is_empty(), "a gradient needs at least one stop");
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).expect("gradient stop position is NaN"));
+        Gradient { stops }
+    }
+
+    /// Samples the gradient at `t`, interpolating between the stops surrounding it
+    ///
+    /// `t` values outside the range of the stops are clamped to the nearest end stop.
+    pub fn get(&self, t: f32) -> C {
+        let first = &self.stops[0];
+        let last = &self.stops[self.stops.len() - 1];
+
+        if t <= first.0 {
+            first.1.clone()
+        } else if t >= last.0 {
+            last.1.clone()
+        } else {
+            let i = self.stops.iter().position(|(pos, _)| *pos > t).unwrap();
+            let (pos_a, color_a) = &self.stops[i - 1];
+            let (pos_b, color_b) = &self.stops[i];
+
+            let local_t = (t - pos_a) / (pos_b - pos_a);
+            color_a.clone().lerp(color_b.clone(), local_t)
+        }
+    }
+
+    /// Samples `n` evenly spaced colors across the gradient's full range
+    pub fn take(&self, n: usize) -> Vec<C> {
+        if n == 0 {
+            return Vec::new();
+        } else if n == 1 {
+            return vec![self.get(self.stops[0].0)];
+        }
+
+        let start = self.stops[0].0;
+        let end = self.stops[self.stops.len() - 1].0;
+
+        (0..n)
+            .map(|i| start + (end - start) * (i as f32) / ((n - 1) as f32))
+            .map(|t| self.get(t))
+            .collect()
+    }
+}
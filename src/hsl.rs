@@ -0,0 +1,264 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use num_traits::{Float, NumCast, One, ToPrimitive, Zero};
+
+use crate::*;
+
+/// A HSL color
+///
+/// ## Type arguments
+/// `H` is the type of hue channel, `T` is the type of the saturation and lightness channels.
+///
+/// `S` is this color's colorspace.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct HSLColor<H, T, S> {
+    pub h: H,
+    pub s: T,
+    pub l: T,
+    _space: PhantomData<S>
+}
+
+impl<H, T, S> HSLColor<H, T, S> {
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (H, T, T) {
+        (self.h, self.s, self.l)
+    }
+}
+
+impl<H, T, S> HSLColor<H, T, S>
+    where Self: Color
+{
+    /// Create a new HSL value.
+    ///
+    /// The value is normalized on creation.
+    pub fn new(h: H, s: T, l: T) -> Self {
+        HSLColor { h, s, l, _space: PhantomData }.normalize()
+    }
+}
+
+impl<H: Channel, T: Channel, S> HSLColor<H, T, S> {
+    /// Transform this color into RGB form
+    ///
+    /// This should be done to a normalized HSL color.
+    ///
+    /// The chroma/lightness math is carried out in `T::Float`, so a high-precision color (eg.
+    /// one with `f64` channels) doesn't get truncated through an `f32` bottleneck in the middle
+    /// of the conversion.
+    pub fn rgb(self) -> RGBColor<T, S> {
+        // `AngleDeg` only comes in an `f32` flavor, so the hue is extracted through `f32` - but
+        // everything past that extraction, including the /60 division, stays in `T::Float`.
+        let h_deg: f32 = cuwtf(self.h.conv::<AngleDeg<f32>>());
+        let sixty: T::Float = NumCast::from(60.0).unwrap();
+        let h: T::Float = <T::Float as NumCast>::from(h_deg).unwrap() / sixty;
+        let s: T::Float = NumCast::from(self.s).unwrap();
+        let l: T::Float = NumCast::from(self.l).unwrap();
+
+        let zero = T::Float::zero();
+        let one = T::Float::one();
+        let two = one + one;
+
+        // chroma, second largest component offset and the amount to add back to every channel
+        let c = (one - (two * l - one).abs()) * s;
+        let x = c * (one - (h % two - one).abs());
+        let m = l - c / two;
+
+        let (r, g, b) =
+            match h.to_u8() {
+                Some(0)           => (   c,    x, zero),
+                Some(1)           => (   x,    c, zero),
+                Some(2)           => (zero,    c,    x),
+                Some(3)           => (zero,    x,    c),
+                Some(4)           => (   x, zero,    c),
+                Some(5) | Some(6) => (   c, zero,    x),
+                _                 => panic!("Invalid hue value: {:?}", h_deg)
+            };
+
+        (NumCast::from(r + m).unwrap(),
+         NumCast::from(g + m).unwrap(),
+         NumCast::from(b + m).unwrap()).into()
+    }
+
+    /// Convert this color into the equivalent HSV representation
+    #[inline]
+    pub fn hsv(self) -> HSVColor<H, T, S> {
+        self.rgb().hsv().conv()
+    }
+
+    #[inline]
+    pub fn conv<H2: Channel, T2: Channel>(self) -> HSLColor<H2, T2, S> {
+        HSLColor { h: self.h.conv(), s: self.s.conv(), l: self.l.conv(), _space: PhantomData }
+    }
+}
+
+impl<T: Channel, S> RGBColor<T, S> {
+    /// Transform this color into HSL form
+    ///
+    /// The chroma/lightness math is carried out in `T::Float`, so a high-precision color (eg.
+    /// one with `f64` channels) doesn't get truncated through an `f32` bottleneck in the middle
+    /// of the conversion.
+    pub fn hsl(self) -> HSLColor<T, T, S> {
+        let r: T::Float = NumCast::from(self.r).unwrap();
+        let g: T::Float = NumCast::from(self.g).unwrap();
+        let b: T::Float = NumCast::from(self.b).unwrap();
+
+        let zero = T::Float::zero();
+        let one = T::Float::one();
+        let two = one + one;
+        let six = two + two + two;
+        let sixty: T::Float = NumCast::from(60.0).unwrap();
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let l = (max + min) / two;
+
+        let s = if delta == zero {
+            zero
+        } else {
+            delta / (one - (two * l - one).abs())
+        };
+
+        let h = if delta == zero {
+            zero
+        } else if max == r {
+            let h = (g - b) / delta;
+            sixty * ((h % six + six) % six)
+        } else if max == g {
+            sixty * ((b - r) / delta + two)
+        } else {
+            sixty * ((r - g) / delta + (two + two))
+        };
+
+        let h: f32 = NumCast::from(h).unwrap();
+        HSLColor::new(AngleDeg(h).conv(), NumCast::from(s).unwrap(), NumCast::from(l).unwrap())
+    }
+}
+
+impl<H: Channel, T: Channel, S> Color for HSLColor<H, T, S>
+    where Self: Clone
+{
+    /// Normalize the color's values by normalizing the hue and zeroing the unnecessary channels
+    ///
+    /// If lightness is zero or at maximum, black or white is returned respectively.
+    /// If saturation channel is zero, hue is set to zero.
+    ///
+    /// Otherwise the color itself is returned, with it's channels put to their proper ranges
+    fn normalize(self) -> Self {
+        let (h, s, l) = self.tuple();
+        if l == T::ch_zero() || l == T::ch_max() || s == T::ch_zero() {
+            HSLColor {
+                h: H::ch_zero(),
+                s: T::ch_zero(),
+                l: l.to_range(),
+                _space: PhantomData
+            }
+        } else {
+            HSLColor {
+                h: h.to_range(),
+                s: s.to_range(),
+                l: l.to_range(),
+                _space: PhantomData
+            }
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        let (h, s, l) = self.clone().tuple();
+        let (h0, t0) = (H::ch_zero(), T::ch_zero());
+
+        if !h.in_range() || !s.in_range() || !l.in_range() {
+            false
+        } else if l == t0 || l == T::ch_max() {
+            // black or white
+            if h == h0 && s == t0 { true }
+            else { false }
+        } else if s == t0 {
+            // a grey color
+            if h == h0 { true }
+            else { false }
+        } else { true }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HSLColor<H, T, SRGBSpace>
+    where Self: Color
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        use self::BaseColor::*;
+
+        let f = |h: f32, s: f32, l: f32|
+            Self::new(AngleDeg(h).conv(), s.conv(), l.conv());
+
+        match base_color {
+            Black   => f(  0.0, 0.0, 0.0),
+            Grey    => f(  0.0, 0.0, 0.5),
+            White   => f(  0.0, 0.0, 1.0),
+            Red     => f(  0.0, 1.0, 0.5),
+            Yellow  => f( 60.0, 1.0, 0.5),
+            Green   => f(120.0, 1.0, 0.5),
+            Cyan    => f(180.0, 1.0, 0.5),
+            Blue    => f(240.0, 1.0, 0.5),
+            Magenta => f(300.0, 1.0, 0.5),
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HSLColor<H, T, LinearSpace> {
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<f32, LinearSpace>::from(base_color).hsl().conv()
+    }
+}
+
+impl<H: Channel, T: Channel, S> From<(H, T, T)> for HSLColor<H, T, S>
+    where Self: Color
+{
+    fn from(tuple: (H, T, T)) -> Self {
+        let (h, s, l) = tuple;
+        HSLColor::new(h, s, l)
+    }
+}
+
+impl<H: Clone + Channel, T: Clone + Channel, S> From<&(H, T, T)> for HSLColor<H, T, S>
+    where Self: Color
+{
+    fn from(tuple: &(H, T, T)) -> Self {
+        let (h, s, l) = tuple.clone();
+        HSLColor::new(h, s, l)
+    }
+}
+
+impl<H: Channel, T: Channel, S> Default for HSLColor<H, T, S> {
+    fn default() -> Self {
+        HSLColor {
+            h: H::ch_zero(),
+            s: T::ch_zero(),
+            l: T::ch_zero(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<H: Clone, T: Clone, S> Clone for HSLColor<H, T, S> {
+    fn clone(&self) -> Self {
+        HSLColor {
+            h: self.h.clone(),
+            s: self.s.clone(),
+            l: self.l.clone(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<H: Copy, T: Copy, S> Copy for HSLColor<H, T, S> {}
+
+// TODO make more generic
+impl<S> fmt::Display for HSLColor<f32, f32, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>5.1}°,{:>5.1}%,{:>5.1}%", self.h, self.s * 100.0, self.l * 100.0)
+    }
+}
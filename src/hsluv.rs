@@ -0,0 +1,120 @@
+use crate::*;
+
+/// XYZ->RGB matrix rows, the same coefficients [`XYZColor::rgb`] uses - used to find the sRGB gamut boundary
+/// for a given lightness and hue.
+const RGB_MATRIX: [[f32; 3]; 3] = [
+    [ 3.2406, -1.5372, -0.4986],
+    [-0.9689,  1.8758,  0.0415],
+    [ 0.0557, -0.2040,  1.0570],
+];
+
+/// A perceptually uniform HSLuv color
+///
+/// HSLuv reparametrizes CIELUV as a cylinder: `h` is the CIE LCH(uv) hue in degrees, `l` is
+/// `L*` (0..100) and `s` is the percentage (0..100) of the maximum chroma reachable by the
+/// sRGB gamut at that lightness and hue, so `s = 100` always stays inside the gamut.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct HSLuv {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl HSLuv {
+    /// Deconstructs this color into a tuple of it's components
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, f32) {
+        (self.h, self.s, self.l)
+    }
+
+    /// Creates a new HSLuv value, normalizing the hue and clamping saturation/lightness
+    pub fn new(h: f32, s: f32, l: f32) -> Self {
+        HSLuv { h: h.rem_euclid(360.0), s: s.max(0.0).min(100.0), l: l.max(0.0).min(100.0) }
+    }
+
+    /// Converts this color into CIELUV
+    pub fn luv(self) -> LuvColor {
+        let (h, s, l) = self.tuple();
+
+        if l > 99.999_999 {
+            return LuvColor { l: 100.0, u: 0.0, v: 0.0 };
+        } else if l < 0.000_000_1 {
+            return LuvColor { l: 0.0, u: 0.0, v: 0.0 };
+        }
+
+        let c_max = max_chroma_for_lh(l, h);
+        LuvColor::from_lch(l, c_max * s / 100.0, h)
+    }
+
+    /// Converts this color into RGB form, by way of CIELUV, CIE XYZ and linear RGB
+    ///
+    /// `std_encode`/`std_decode` still pivot through `f32` internally - that's in `RGBColor`'s
+    /// own module, not this one, so fixing it is out of scope here.
+    pub fn rgb<T: Channel>(self) -> RGBColor<T, SRGBSpace> {
+        self.luv().xyz().rgb::<f32>().std_encode().conv()
+    }
+}
+
+impl LuvColor {
+    /// Converts this CIELUV color into the perceptually uniform HSLuv cylinder
+    pub fn hsluv(self) -> HSLuv {
+        let (c, h) = self.lch();
+        let l = self.l;
+
+        if l > 99.999_999 {
+            return HSLuv { h, s: 0.0, l: 100.0 };
+        } else if l < 0.000_000_1 {
+            return HSLuv { h, s: 0.0, l: 0.0 };
+        }
+
+        let c_max = max_chroma_for_lh(l, h);
+        HSLuv { h, s: c / c_max * 100.0, l }
+    }
+}
+
+impl<T: Channel> RGBColor<T, SRGBSpace> {
+    /// Converts this color into the perceptually uniform HSLuv cylinder, by way of linear RGB,
+    /// CIE XYZ and CIELUV
+    pub fn hsluv(self) -> HSLuv {
+        self.conv::<f32>().std_decode().xyz().luv().hsluv()
+    }
+}
+
+/// The maximum chroma reachable by the sRGB gamut at lightness `l` and hue `h` (in degrees)
+///
+/// Finds it by intersecting the hue ray (in the *u*,*v* plane) with the six lines that bound
+/// the gamut cube's projection at that lightness - one pair (channel at 0 and channel at 1)
+/// per RGB primary - and taking the closest (smallest positive) intersection.
+fn max_chroma_for_lh(l: f32, h: f32) -> f32 {
+    let hrad = h.to_radians();
+
+    gamut_boundary_lines(l)
+        .iter()
+        .filter_map(|&(slope, intercept)| {
+            let length = intercept / (hrad.sin() - slope * hrad.cos());
+            if length >= 0.0 { Some(length) } else { None }
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Returns the six `(slope, intercept)` lines (in the *u*,*v* plane) bounding the sRGB gamut
+/// cube's cross-section at lightness `l`
+fn gamut_boundary_lines(l: f32) -> [(f32, f32); 6] {
+    let sub1 = (l + 16.0).powi(3) / 1_560_896.0;
+    let sub2 = if sub1 > 0.008856 { sub1 } else { l / 903.3 };
+
+    let mut lines = [(0.0, 0.0); 6];
+    let mut i = 0;
+    for &[m1, m2, m3] in &RGB_MATRIX {
+        for &t in &[0.0, 1.0] {
+            let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+            let top2 = (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2
+                - 769_860.0 * t * l;
+            let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+
+            lines[i] = (top1 / bottom, top2 / bottom);
+            i += 1;
+        }
+    }
+    lines
+}
@@ -1,6 +1,8 @@
 use std::fmt;
 use std::marker::PhantomData;
 
+use num_traits::{Float, NumCast, One, ToPrimitive, Zero};
+
 use crate::*;
 
 /// A HSV color
@@ -41,29 +43,48 @@ impl<H: Channel, T: Channel, S> HSVColor<H, T, S> {
     /// Transform this color into RGB form
     ///
     /// This should be done to a normalized HSV color.
+    ///
+    /// The chroma/value math is carried out in `T::Float`, so a high-precision color (eg. one
+    /// with `f64` channels) doesn't get truncated through an `f32` bottleneck in the middle of
+    /// the conversion.
     pub fn rgb(self) -> RGBColor<T, S> {
-        let h = cuwtf(self.h.conv::<AngleDeg<f32>>()) / 60.0;
-        let (s, v) = (cuwtf(self.s), cuwtf(self.v));
+        // `AngleDeg` only comes in an `f32` flavor, so the hue is extracted through `f32` - but
+        // everything past that extraction, including the /60 division, stays in `T::Float`.
+        let h_deg: f32 = cuwtf(self.h.conv::<AngleDeg<f32>>());
+        let sixty: T::Float = NumCast::from(60.0).unwrap();
+        let h: T::Float = <T::Float as NumCast>::from(h_deg).unwrap() / sixty;
+        let s: T::Float = NumCast::from(self.s).unwrap();
+        let v: T::Float = NumCast::from(self.v).unwrap();
+
+        let zero = T::Float::zero();
+        let one = T::Float::one();
+        let two = one + one;
 
         // largest, second largest and the smallest component
         let c = s * v;
-        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let x = c * (one - (h % two - one).abs());
         let min = v - c;
 
         let (r, g, b) =
-            match h as u8 {
-                0   => (  c,   x, 0.0),
-                1   => (  x,   c, 0.0),
-                2   => (0.0,   c,   x),
-                3   => (0.0,   x,   c),
-                4   => (  x, 0.0,   c),
-                5|6 => (  c, 0.0,   x),
-                _   => panic!("Invalid hue value: {:?}", h)
+            match h.to_u8() {
+                Some(0)           => (   c,    x, zero),
+                Some(1)           => (   x,    c, zero),
+                Some(2)           => (zero,    c,    x),
+                Some(3)           => (zero,    x,    c),
+                Some(4)           => (   x, zero,    c),
+                Some(5) | Some(6) => (   c, zero,    x),
+                _                 => panic!("Invalid hue value: {:?}", h_deg)
             };
 
-        (cuwf::<T>(r + min),
-         cuwf::<T>(g + min),
-         cuwf::<T>(b + min)).into()
+        (NumCast::from(r + min).unwrap(),
+         NumCast::from(g + min).unwrap(),
+         NumCast::from(b + min).unwrap()).into()
+    }
+
+    /// Convert this color into the equivalent HSL representation
+    #[inline]
+    pub fn hsl(self) -> HSLColor<H, T, S> {
+        self.rgb().hsl().conv()
     }
 
     #[inline]
@@ -0,0 +1,226 @@
+use std::marker::PhantomData;
+
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::*;
+
+/// A HWB (hue, whiteness, blackness) color
+///
+/// HWB is closely related to HSV, but tends to be more intuitive for color pickers since
+/// whiteness and blackness are mixed directly into the hue.
+///
+/// ## Type arguments
+/// `H` is the type of the hue channel, `T` is the type of the whiteness and blackness channels.
+///
+/// `S` is this color's colorspace.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct HWBColor<H, T, S> {
+    pub h: H,
+    pub w: T,
+    pub b: T,
+    _space: PhantomData<S>
+}
+
+impl<H, T, S> HWBColor<H, T, S> {
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (H, T, T) {
+        (self.h, self.w, self.b)
+    }
+}
+
+impl<H, T, S> HWBColor<H, T, S>
+    where Self: Color
+{
+    /// Create a new HWB value.
+    ///
+    /// The value is normalized on creation.
+    pub fn new(h: H, w: T, b: T) -> Self {
+        HWBColor { h, w, b, _space: PhantomData }.normalize()
+    }
+}
+
+impl<H: Channel, T: Channel, S> HWBColor<H, T, S> {
+    /// Convert this color into the equivalent HSV representation
+    ///
+    /// The whiteness/blackness math is carried out in `T::Float`, so a high-precision color
+    /// (eg. one with `f64` channels) doesn't get truncated through an `f32` bottleneck in the
+    /// middle of the conversion.
+    pub fn hsv(self) -> HSVColor<H, T, S> {
+        let w: T::Float = NumCast::from(self.w).unwrap();
+        let b: T::Float = NumCast::from(self.b).unwrap();
+
+        let zero = T::Float::zero();
+        let one = T::Float::one();
+
+        // scale whiteness and blackness down proportionally if they overflow
+        let (w, b) = if w + b > one {
+            let scale = one / (w + b);
+            (w * scale, b * scale)
+        } else {
+            (w, b)
+        };
+
+        let v = one - b;
+        let s = if v == zero { zero } else { one - w / v };
+
+        HSVColor { h: self.h, s: NumCast::from(s).unwrap(), v: NumCast::from(v).unwrap(), _space: PhantomData }
+    }
+
+    /// Transform this color into RGB form
+    ///
+    /// This should be done to a normalized HWB color.
+    #[inline]
+    pub fn rgb(self) -> RGBColor<T, S> {
+        self.hsv().rgb()
+    }
+
+    #[inline]
+    pub fn conv<H2: Channel, T2: Channel>(self) -> HWBColor<H2, T2, S> {
+        HWBColor { h: self.h.conv(), w: self.w.conv(), b: self.b.conv(), _space: PhantomData }
+    }
+}
+
+impl<H: Channel, T: Channel, S> HSVColor<H, T, S> {
+    /// Convert this color into the equivalent HWB representation
+    ///
+    /// The whiteness/blackness math is carried out in `T::Float`, so a high-precision color
+    /// (eg. one with `f64` channels) doesn't get truncated through an `f32` bottleneck in the
+    /// middle of the conversion.
+    pub fn hwb(self) -> HWBColor<H, T, S> {
+        let s: T::Float = NumCast::from(self.s).unwrap();
+        let v: T::Float = NumCast::from(self.v).unwrap();
+        let one = T::Float::one();
+
+        let w = (one - s) * v;
+        let b = one - v;
+
+        HWBColor { h: self.h, w: NumCast::from(w).unwrap(), b: NumCast::from(b).unwrap(), _space: PhantomData }
+    }
+}
+
+impl<H: Channel, T: Channel, S> Color for HWBColor<H, T, S>
+    where Self: Clone
+{
+    /// Normalize the color's values by normalizing the hue and clamping whiteness/blackness
+    ///
+    /// If whiteness and blackness together cover the whole range or more, the color collapses
+    /// into the grey they describe (keeping their ratio) and the hue is zeroed.
+    ///
+    /// The whiteness/blackness math is carried out in `T::Float`, so a high-precision color
+    /// (eg. one with `f64` channels) doesn't get truncated through an `f32` bottleneck.
+    fn normalize(self) -> Self {
+        let overflows = {
+            let (_, w, b) = self.clone().tuple();
+            let w: T::Float = NumCast::from(w.clamp()).unwrap();
+            let b: T::Float = NumCast::from(b.clamp()).unwrap();
+            let ch_max: T::Float = NumCast::from(T::ch_max()).unwrap();
+            w + b >= ch_max
+        };
+
+        let (h, w, b) = self.tuple();
+        let (w, b) = (w.clamp(), b.clamp());
+
+        if overflows {
+            let w_f: T::Float = NumCast::from(w).unwrap();
+            let b_f: T::Float = NumCast::from(b).unwrap();
+            let ch_max: T::Float = NumCast::from(T::ch_max()).unwrap();
+            let w_ratio = w_f / (w_f + b_f);
+
+            HWBColor {
+                h: H::ch_zero(),
+                w: NumCast::from(w_ratio * ch_max).unwrap(),
+                b: NumCast::from((T::Float::one() - w_ratio) * ch_max).unwrap(),
+                _space: PhantomData
+            }
+        } else {
+            HWBColor {
+                h: h.to_range(),
+                w,
+                b,
+                _space: PhantomData
+            }
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        let (h, w, b) = self.clone().tuple();
+        let h0 = H::ch_zero();
+
+        if !h.in_range() || !w.in_range() || !b.in_range() {
+            false
+        } else {
+            let w: T::Float = NumCast::from(w).unwrap();
+            let b: T::Float = NumCast::from(b).unwrap();
+            let ch_max: T::Float = NumCast::from(T::ch_max()).unwrap();
+
+            if w + b > ch_max {
+                false
+            } else if w + b == ch_max {
+                h == h0
+            } else {
+                true
+            }
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HWBColor<H, T, SRGBSpace>
+    where Self: Color
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        HSVColor::<H, T, SRGBSpace>::from(base_color).hwb()
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HWBColor<H, T, LinearSpace>
+    where Self: Color
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<f32, LinearSpace>::from(base_color).hsv().conv::<H, T>().hwb()
+    }
+}
+
+impl<H: Channel, T: Channel, S> From<(H, T, T)> for HWBColor<H, T, S>
+    where Self: Color
+{
+    fn from(tuple: (H, T, T)) -> Self {
+        let (h, w, b) = tuple;
+        HWBColor::new(h, w, b)
+    }
+}
+
+impl<H: Clone + Channel, T: Clone + Channel, S> From<&(H, T, T)> for HWBColor<H, T, S>
+    where Self: Color
+{
+    fn from(tuple: &(H, T, T)) -> Self {
+        let (h, w, b) = tuple.clone();
+        HWBColor::new(h, w, b)
+    }
+}
+
+impl<H: Channel, T: Channel, S> Default for HWBColor<H, T, S> {
+    fn default() -> Self {
+        HWBColor {
+            h: H::ch_zero(),
+            w: T::ch_zero(),
+            b: T::ch_max(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<H: Clone, T: Clone, S> Clone for HWBColor<H, T, S> {
+    fn clone(&self) -> Self {
+        HWBColor {
+            h: self.h.clone(),
+            w: self.w.clone(),
+            b: self.b.clone(),
+            _space: PhantomData
+        }
+    }
+}
+
+impl<H: Copy, T: Copy, S> Copy for HWBColor<H, T, S> {}
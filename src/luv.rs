@@ -0,0 +1,99 @@
+use crate::*;
+
+/// CIE 1976 L*u*v* epsilon: `(6/29)^3`
+const EPSILON: f32 = 0.008856;
+/// CIE 1976 L*u*v* kappa: `(29/3)^3`
+const KAPPA: f32 = 903.3;
+
+/// D65 reference white `u'`, `v'` projective chromaticity
+const WHITE_U_PRIME: f32 = 0.1978;
+const WHITE_V_PRIME: f32 = 0.4683;
+
+/// A CIE 1976 L*u*v* (CIELUV) color
+///
+/// Like `XYZColor`, this is kept as plain `f32` coordinates: `l` ranges roughly `0..100` and
+/// `u`/`v` are signed and roughly `-100..100`, neither of which fits the `Channel` abstraction.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct LuvColor {
+    pub l: f32,
+    pub u: f32,
+    pub v: f32,
+}
+
+impl LuvColor {
+    /// Deconstructs this color into a tuple of it's components
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, f32) {
+        (self.l, self.u, self.v)
+    }
+
+    /// Converts this color into CIE LCH(uv) polar form: chroma and hue (in degrees)
+    pub fn lch(self) -> (f32, f32) {
+        let c = (self.u * self.u + self.v * self.v).sqrt();
+        let h = self.v.atan2(self.u).to_degrees();
+        (c, if h < 0.0 { h + 360.0 } else { h })
+    }
+
+    /// Builds a `LuvColor` from lightness, chroma and hue (in degrees)
+    pub fn from_lch(l: f32, c: f32, h: f32) -> Self {
+        let h = h.to_radians();
+        LuvColor { l, u: c * h.cos(), v: c * h.sin() }
+    }
+}
+
+impl XYZColor {
+    /// Converts this CIE XYZ color into CIELUV, relative to the D65 white point
+    pub fn luv(self) -> LuvColor {
+        let (x, y, z) = self.tuple();
+
+        let l = l_star(y);
+        let denom = x + 15.0 * y + 3.0 * z;
+        let (u_prime, v_prime) = if denom == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (4.0 * x / denom, 9.0 * y / denom)
+        };
+
+        LuvColor {
+            l,
+            u: 13.0 * l * (u_prime - WHITE_U_PRIME),
+            v: 13.0 * l * (v_prime - WHITE_V_PRIME),
+        }
+    }
+}
+
+impl LuvColor {
+    /// Converts this CIELUV color back into CIE XYZ, relative to the D65 white point
+    pub fn xyz(self) -> XYZColor {
+        if self.l == 0.0 {
+            return XYZColor::default();
+        }
+
+        let u_prime = self.u / (13.0 * self.l) + WHITE_U_PRIME;
+        let v_prime = self.v / (13.0 * self.l) + WHITE_V_PRIME;
+
+        let y = y_from_l_star(self.l);
+        let x = y * 9.0 * u_prime / (4.0 * v_prime);
+        let z = y * (12.0 - 3.0 * u_prime - 20.0 * v_prime) / (4.0 * v_prime);
+
+        XYZColor { x, y, z }
+    }
+}
+
+/// `L* = 116 f(Y) - 16`, the CIE lightness response curve
+pub(crate) fn l_star(y: f32) -> f32 {
+    if y > EPSILON {
+        116.0 * y.cbrt() - 16.0
+    } else {
+        KAPPA * y
+    }
+}
+
+/// The inverse of [`l_star`]
+pub(crate) fn y_from_l_star(l: f32) -> f32 {
+    if l > KAPPA * EPSILON {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / KAPPA
+    }
+}
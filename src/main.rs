@@ -45,4 +45,16 @@ fn main() {
         print!("{}", col.ansi_bgcolor("_"));
     }
     println!();
+
+    let gradient = Gradient::new(vec![
+        (0.0, RGBColor::<f32, SRGBSpace>::from(BaseColor::Red)),
+        (0.5, RGBColor::<f32, SRGBSpace>::from(BaseColor::Yellow)),
+        (1.0, RGBColor::<f32, SRGBSpace>::from(BaseColor::Blue)),
+    ]);
+
+    print!("\ngradient preview: ");
+    for col in gradient.take(LEN) {
+        print!("{}", col.conv::<u8>().ansi_bgcolor("_"));
+    }
+    println!();
 }
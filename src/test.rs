@@ -9,6 +9,49 @@ fn rgb_to_hsv() {
     assert_eq!(rgb, rgb2);
 }
 
+#[test]
+fn rgb_to_hsl() {
+    let rgb = SRGB24Color::new(128, 255, 55);
+    let hsl = rgb.conv::<f32>().hsl().normalize();
+    let rgb2 = hsl.rgb().conv();
+
+    assert_eq!(rgb, rgb2);
+}
+
+#[test]
+fn rgb_to_hwb() {
+    let rgb = SRGB24Color::new(128, 255, 55);
+    let hwb = rgb.conv::<f32>().hsv().hwb().normalize();
+    let rgb2 = hwb.rgb().conv();
+
+    assert_eq!(rgb, rgb2);
+}
+
+#[test]
+fn rgb_to_cmyk() {
+    let rgb = SRGB24Color::new(128, 255, 55);
+    let cmyk = rgb.conv::<f32>().cmyk().normalize();
+    let rgb2 = cmyk.rgb().conv();
+
+    assert_eq!(rgb, rgb2);
+}
+
+#[test]
+fn rgb_to_hsluv() {
+    let rgb = SRGB24Color::new(128, 255, 55);
+    let hsluv = rgb.conv::<f32>().hsluv();
+    let rgb2: SRGB24Color = hsluv.rgb();
+
+    let (r1, g1, b1) = rgb.tuple();
+    let (r2, g2, b2) = rgb2.tuple();
+
+    // the gamut boundary search in HSLuv::rgb() only recovers the original channels to within
+    // rounding error, so allow each channel to be off by one 8-bit step
+    assert!((r1 as i16 - r2 as i16).abs() <= 1);
+    assert!((g1 as i16 - g2 as i16).abs() <= 1);
+    assert!((b1 as i16 - b2 as i16).abs() <= 1);
+}
+
 #[test]
 fn srgb_to_linear() {
     let srgb = SRGB24Color::new(128, 255, 55);
@@ -59,6 +102,57 @@ fn into_iterator() {
     assert_eq!(i2.next(), None);
 }
 
+#[test]
+fn alpha_wraps_color() {
+    let rgb: RGBColor<u8, SRGBSpace> = (10, 20, 30).into();
+    let a = rgb.with_alpha(200u8);
+
+    assert_eq!(a.color, rgb);
+    assert_eq!(a.alpha, 200);
+    assert!(a.is_normal());
+    assert_eq!(Alpha::new(rgb, 200u8), a);
+
+    assert_eq!(a.split(), (rgb, 200));
+
+    let a2: Alpha<RGBColor<f32, SRGBSpace>, f32> = a.conv();
+    assert_eq!(a2.color, rgb.conv());
+    assert_eq!(a2.alpha, 200u8.conv());
+
+    // alpha is yielded last, after the wrapped color's own channels
+    let mut iter = a.into_iter();
+    assert_eq!(iter.next(), Some(10));
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(iter.next(), Some(30));
+    assert_eq!(iter.next(), Some(200));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn lerp_hue_wraparound() {
+    let a = AngleDeg(350.0);
+    let b = AngleDeg(10.0);
+
+    // the shorter arc from 350° to 10° crosses 0°, not the long way through 180°
+    assert_eq!(a.lerp(b, 0.0).0, 350.0);
+    assert_eq!(a.lerp(b, 0.5).0, 0.0);
+    assert_eq!(a.lerp(b, 1.0).0, 10.0);
+}
+
+#[test]
+fn gradient_get_and_take() {
+    let red: RGBColor<f32, SRGBSpace> = (1.0, 0.0, 0.0).into();
+    let blue: RGBColor<f32, SRGBSpace> = (0.0, 0.0, 1.0).into();
+    let gradient = Gradient::new(vec![(0.0, red), (1.0, blue)]);
+
+    assert_eq!(gradient.get(0.5), (0.5, 0.0, 0.5).into());
+
+    // t outside the stops' range clamps to the nearest end stop
+    assert_eq!(gradient.get(-1.0), red);
+    assert_eq!(gradient.get(2.0), blue);
+
+    assert_eq!(gradient.take(3), vec![red, (0.5, 0.0, 0.5).into(), blue]);
+}
+
 #[test]
 fn angle_conversion() {
     use std::f32::consts::PI;
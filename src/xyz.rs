@@ -0,0 +1,47 @@
+use crate::*;
+
+/// A CIE 1931 XYZ tristimulus value
+///
+/// Unlike the crate's other color models, XYZ isn't a container for display-channel data -
+/// its components are unbounded relative to the sRGB/D65 white point (`Y = 1` for white), so
+/// it's kept as plain `f32` coordinates rather than going through `Channel`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct XYZColor {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl XYZColor {
+    /// Deconstructs this color into a tuple of it's components
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, f32) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl<T: Channel> RGBColor<T, LinearSpace> {
+    /// Converts this linear RGB color into CIE XYZ using the sRGB/D65 matrix
+    pub fn xyz(self) -> XYZColor {
+        let (r, g, b) = (cuwtf(self.r), cuwtf(self.g), cuwtf(self.b));
+
+        XYZColor {
+            x: 0.4124 * r + 0.3576 * g + 0.1805 * b,
+            y: 0.2126 * r + 0.7152 * g + 0.0722 * b,
+            z: 0.0193 * r + 0.1192 * g + 0.9505 * b,
+        }
+    }
+}
+
+impl XYZColor {
+    /// Converts this CIE XYZ color back into linear RGB using the inverse sRGB/D65 matrix
+    pub fn rgb<T: Channel>(self) -> RGBColor<T, LinearSpace> {
+        let (x, y, z) = self.tuple();
+
+        let r =  3.2406 * x - 1.5372 * y - 0.4986 * z;
+        let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
+        let b =  0.0557 * x - 0.2040 * y + 1.0570 * z;
+
+        (cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)).into()
+    }
+}